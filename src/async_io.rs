@@ -0,0 +1,102 @@
+//! IRQ-pin-driven async RX/TX, as an alternative to busy-polling `Status`.
+//!
+//! Instead of spinning on a register read, a dedicated task waits on the
+//! module's IRQ line, decodes the `Status` byte once it falls, and dispatches
+//! each event it carries onto its own `Signal` so `send()` and `receive()`
+//! can be awaited concurrently without stealing each other's wakeups.
+use crate::config::DataPipe;
+use crate::driver::StatusBus;
+use crate::status::{InterruptKind, StatusError};
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::signal::Signal;
+use embedded_hal_async::digital::Wait;
+
+/// The device reported `MAX_RT`: the peer did not acknowledge the payload
+/// after the configured number of retries.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MaxRetries;
+
+/// Error from [`IrqStatus::run`]: either the IRQ pin failed to wait for an
+/// edge, or the [`StatusBus`] failed to read or acknowledge STATUS.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum IrqError<P, S> {
+    /// The IRQ pin returned an error instead of waiting for an edge.
+    Pin(P),
+    /// Reading or writing the STATUS register over the bus failed.
+    Status(S),
+}
+
+/// Decodes IRQ edges into `Status` values and hands each event off to
+/// whichever `send`/`receive` future is currently awaiting one.
+///
+/// `send` and `receive` are fed by independent signals, since a single
+/// shared `Signal` only tracks one registered waker: racing both futures on
+/// one slot lets one `wait()` call steal the other's wakeup, or consume a
+/// status word meant for the other, and stall it forever.
+pub struct IrqStatus<M: RawMutex> {
+    tx: Signal<M, Result<(), MaxRetries>>,
+    rx: Signal<M, Result<DataPipe, StatusError>>,
+}
+
+impl<M: RawMutex> IrqStatus<M> {
+    /// Creates an empty signal pair with no event pending.
+    pub const fn new() -> Self {
+        Self {
+            tx: Signal::new(),
+            rx: Signal::new(),
+        }
+    }
+
+    /// Waits for the IRQ `pin` to fall, reads STATUS over `bus`, dispatches
+    /// every event it carries to whichever future is awaiting `send` or
+    /// `receive`, and acknowledges exactly those events back over `bus`.
+    ///
+    /// The nRF24L01 holds the IRQ line low as long as any of RX_DR/TX_DS/
+    /// MAX_RT remains set, so the events dispatched on one pass must be
+    /// cleared before waiting for the next edge, or the pin never rises
+    /// again and every subsequent `send`/`receive` hangs forever.
+    ///
+    /// Intended to be spawned as its own task for the lifetime of the
+    /// driver. Returns as soon as the pin, the status read, or the
+    /// acknowledgement write fails, rather than swallowing the error and
+    /// spinning.
+    pub async fn run<P, B>(
+        &self,
+        mut pin: P,
+        mut bus: B,
+    ) -> Result<(), IrqError<P::Error, B::Error>>
+    where
+        P: Wait,
+        B: StatusBus,
+    {
+        loop {
+            pin.wait_for_falling_edge().await.map_err(IrqError::Pin)?;
+            let status = bus.read_status().map_err(IrqError::Status)?;
+            let irqs = status.pending_interrupts();
+            for kind in irqs {
+                match kind {
+                    InterruptKind::TransmissionFail => self.tx.signal(Err(MaxRetries)),
+                    InterruptKind::TransmissionOk => self.tx.signal(Ok(())),
+                    InterruptKind::DataReady => match status.data_pipe_available() {
+                        Ok(Some(pipe)) => self.rx.signal(Ok(pipe)),
+                        Ok(None) => {}
+                        Err(e) => self.rx.signal(Err(e)),
+                    },
+                }
+            }
+            bus.write_status(status.ack(irqs))
+                .map_err(IrqError::Status)?;
+        }
+    }
+
+    /// Resolves once the pending transmission completes, `Ok` on `TX_DS`
+    /// or [`MaxRetries`] on `MAX_RT`.
+    pub async fn send(&self) -> Result<(), MaxRetries> {
+        self.tx.wait().await
+    }
+
+    /// Resolves with the pipe a payload is waiting on once `RX_DR` asserts.
+    pub async fn receive(&self) -> Result<DataPipe, StatusError> {
+        self.rx.wait().await
+    }
+}
@@ -0,0 +1,86 @@
+//! Driver wiring on top of the register decoders in `status` and `fifo`.
+use crate::fifo::{resync_rx, resync_tx, FifoLevel};
+use crate::status::{FIFOStatus, Interrupts, Status};
+
+/// Bus access needed to read and acknowledge the STATUS register.
+///
+/// Implemented by whatever transport (SPI, a mock, ...) the driver is built
+/// on top of.
+pub trait StatusBus {
+    /// Error type returned by the underlying transport.
+    type Error;
+    /// Reads the current STATUS register.
+    fn read_status(&mut self) -> Result<Status, Self::Error>;
+    /// Writes `mask` back to STATUS; the device clears any bit set in it
+    /// (write-1-to-clear).
+    fn write_status(&mut self, mask: u8) -> Result<(), Self::Error>;
+}
+
+/// Bus access needed to read the FIFO status used to track occupancy.
+pub trait FifoBus {
+    /// Error type returned by the underlying transport.
+    type Error;
+    /// Reads the current FIFO_STATUS register.
+    fn read_fifo_status(&mut self) -> Result<FIFOStatus, Self::Error>;
+}
+
+/// Thin driver wrapper around a [`StatusBus`]/[`FifoBus`] implementation.
+pub struct Driver<B> {
+    bus: B,
+    tx_level: FifoLevel,
+    rx_level: FifoLevel,
+}
+
+impl<B: StatusBus> Driver<B> {
+    /// Creates a driver over the given bus, assuming both FIFOs start out
+    /// empty.
+    pub fn new(bus: B) -> Self {
+        Self {
+            bus,
+            tx_level: FifoLevel::new(),
+            rx_level: FifoLevel::new(),
+        }
+    }
+
+    /// Acknowledges exactly the events in `irqs` that are currently
+    /// asserted, writing the computed write-1-to-clear mask to STATUS and
+    /// leaving any other pending interrupt untouched.
+    pub fn clear_interrupts(&mut self, irqs: Interrupts) -> Result<(), B::Error> {
+        let status = self.bus.read_status()?;
+        self.bus.write_status(status.ack(irqs))
+    }
+}
+
+impl<B: FifoBus> Driver<B> {
+    /// Current TX FIFO occupancy, for batch-filling the 3-deep TX FIFO
+    /// instead of probing one write at a time.
+    pub fn tx_fifo_level(&self) -> FifoLevel {
+        self.tx_level
+    }
+
+    /// Current RX FIFO occupancy, for knowing how many payloads are
+    /// waiting to be drained without probing one read at a time.
+    pub fn rx_fifo_level(&self) -> FifoLevel {
+        self.rx_level
+    }
+
+    /// Record a payload having been written into the TX FIFO.
+    pub fn record_tx_write(&mut self) {
+        self.tx_level.record_write();
+    }
+
+    /// Record a payload having been read out of the RX FIFO.
+    pub fn record_rx_read(&mut self) {
+        self.rx_level.record_read();
+    }
+
+    /// Resynchronizes both tracked FIFO levels against a freshly read
+    /// FIFO_STATUS: a level snaps to empty or full when FIFO_STATUS reports
+    /// that state, and is left unchanged otherwise.
+    pub fn refresh_fifo_level(&mut self) -> Result<(), B::Error> {
+        let status = self.bus.read_fifo_status()?;
+        resync_tx(&mut self.tx_level, status);
+        resync_rx(&mut self.rx_level, status);
+        Ok(())
+    }
+}
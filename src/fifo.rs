@@ -0,0 +1,148 @@
+//! FIFO occupancy tracking beyond the full/empty flags in `FIFOStatus`.
+use crate::status::FIFOStatus;
+
+/// Depth of the nRF24L01 TX and RX FIFOs.
+const FIFO_DEPTH: u8 = 3;
+
+/// Tracks how many of a 3-deep FIFO's slots are occupied by counting
+/// writes/reads since the last empty/full transition, so callers can batch
+/// up to `FIFO_DEPTH` payloads at once instead of probing one write at a
+/// time.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FifoLevel {
+    occupied: u8,
+}
+
+impl FifoLevel {
+    /// Creates a tracker assuming the FIFO starts out empty.
+    pub fn new() -> Self {
+        Self { occupied: 0 }
+    }
+
+    /// Record a payload having been written into the FIFO.
+    pub fn record_write(&mut self) {
+        self.occupied = (self.occupied + 1).min(FIFO_DEPTH);
+    }
+
+    /// Record a payload having been read out of the FIFO.
+    pub fn record_read(&mut self) {
+        self.occupied = self.occupied.saturating_sub(1);
+    }
+
+    /// Resynchronizes the tracker after observing the FIFO go empty,
+    /// correcting for any write/read this tracker missed.
+    pub fn resync_empty(&mut self) {
+        self.occupied = 0;
+    }
+
+    /// Resynchronizes the tracker after observing the FIFO go full.
+    pub fn resync_full(&mut self) {
+        self.occupied = FIFO_DEPTH;
+    }
+
+    /// Number of slots currently occupied, out of `FIFO_DEPTH`.
+    pub fn occupied(&self) -> u8 {
+        self.occupied
+    }
+
+    /// Number of free slots available to write into.
+    pub fn free(&self) -> u8 {
+        FIFO_DEPTH - self.occupied
+    }
+}
+
+impl Default for FifoLevel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resynchronizes a TX [`FifoLevel`] against a freshly read [`FIFOStatus`],
+/// snapping to a known-good count at the empty/full transitions and
+/// trusting the running count in between.
+pub fn resync_tx(level: &mut FifoLevel, status: FIFOStatus) {
+    if status.tx_empty() {
+        level.resync_empty();
+    } else if status.tx_full() {
+        level.resync_full();
+    }
+}
+
+/// Resynchronizes an RX [`FifoLevel`] against a freshly read [`FIFOStatus`].
+pub fn resync_rx(level: &mut FifoLevel, status: FIFOStatus) {
+    if status.rx_empty() {
+        level.resync_empty();
+    } else if status.rx_full() {
+        level.resync_full();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_empty() {
+        let level = FifoLevel::new();
+        assert_eq!(level.occupied(), 0);
+        assert_eq!(level.free(), FIFO_DEPTH);
+    }
+
+    #[test]
+    fn record_write_saturates_at_fifo_depth() {
+        let mut level = FifoLevel::new();
+        for _ in 0..FIFO_DEPTH + 2 {
+            level.record_write();
+        }
+        assert_eq!(level.occupied(), FIFO_DEPTH);
+        assert_eq!(level.free(), 0);
+    }
+
+    #[test]
+    fn record_read_saturates_at_zero() {
+        let mut level = FifoLevel::new();
+        level.record_write();
+        level.record_read();
+        level.record_read();
+        assert_eq!(level.occupied(), 0);
+    }
+
+    #[test]
+    fn resync_empty_and_full_snap_to_known_values() {
+        let mut level = FifoLevel::new();
+        level.record_write();
+        level.resync_full();
+        assert_eq!(level.occupied(), FIFO_DEPTH);
+
+        level.resync_empty();
+        assert_eq!(level.occupied(), 0);
+    }
+
+    #[test]
+    fn resync_tx_snaps_to_empty_or_full_from_fifo_status() {
+        let mut level = FifoLevel::new();
+        level.record_write();
+
+        // tx_empty bit set (bit 4).
+        resync_tx(&mut level, FIFOStatus::from(0b0001_0000));
+        assert_eq!(level.occupied(), 0);
+
+        // tx_full bit set (bit 5).
+        resync_tx(&mut level, FIFOStatus::from(0b0010_0000));
+        assert_eq!(level.occupied(), FIFO_DEPTH);
+    }
+
+    #[test]
+    fn resync_rx_snaps_to_empty_or_full_from_fifo_status() {
+        let mut level = FifoLevel::new();
+        level.record_write();
+
+        // rx_empty bit set (bit 0).
+        resync_rx(&mut level, FIFOStatus::from(0b0000_0001));
+        assert_eq!(level.occupied(), 0);
+
+        // rx_full bit set (bit 1).
+        resync_rx(&mut level, FIFOStatus::from(0b0000_0010));
+        assert_eq!(level.occupied(), FIFO_DEPTH);
+    }
+}
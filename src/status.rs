@@ -12,6 +12,17 @@ pub struct Status(u8);
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct FIFOStatus(u8);
 
+/// Errors that can arise while decoding a [`Status`] byte read back from the
+/// device. A glitchy SPI transfer can corrupt the status word, and callers
+/// should be able to react to that instead of the driver aborting.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StatusError {
+    /// The status byte itself is invalid (see [`Status::is_valid()`]).
+    InvalidStatus,
+    /// The pipe field held the reserved value `0b110`.
+    InvalidPipe,
+}
+
 impl Status {
     /// Create a status obj with all the flags turned on.
     pub fn flags() -> Self {
@@ -38,13 +49,17 @@ impl Status {
     pub fn reached_max_retries(&self) -> bool {
         (self.0 >> 4) & 1 != 0
     }
-    /// Returns data pipe number for the payload availbe for reading
-    /// or None if RX FIFO is empty.
-    pub fn data_pipe_available(&self) -> Option<DataPipe> {
+    /// Returns data pipe number for the payload availbe for reading,
+    /// `None` if the RX FIFO is empty, or a [`StatusError`] if the status
+    /// itself is invalid or the pipe field holds a reserved value.
+    pub fn data_pipe_available(&self) -> Result<Option<DataPipe>, StatusError> {
+        if !self.is_valid() {
+            return Err(StatusError::InvalidStatus);
+        }
         match (self.0 >> 1) & 0b111 {
-            x @ 0..=5 => Some(x.into()),
-            6 => panic!(),
-            7 => None,
+            x @ 0..=5 => Ok(Some(x.into())),
+            6 => Err(StatusError::InvalidPipe),
+            7 => Ok(None),
             _ => unreachable!(), // because we AND the value
         }
     }
@@ -52,8 +67,44 @@ impl Status {
     pub fn tx_full(&self) -> bool {
         (self.0 & 0b1) != 0
     }
+    /// Returns a byte with only the currently-asserted interrupt bits set,
+    /// suitable for writing back to the STATUS register to clear them
+    /// (the device clears RX_DR/TX_DS/MAX_RT by writing `1` to each bit).
+    pub fn clear_mask(&self) -> u8 {
+        self.0 & Interrupts::all().value()
+    }
+    /// Returns the write-1-to-clear mask needed to acknowledge only the
+    /// events in `irqs` that are currently asserted, leaving any other
+    /// pending interrupt untouched.
+    pub fn ack(&self, irqs: Interrupts) -> u8 {
+        self.clear_mask() & irqs.value()
+    }
+    /// Folds `reached_max_retries()`/`data_sent()`/`data_ready()` into a
+    /// single `Interrupts` value describing every event this status raised.
+    pub fn pending_interrupts(&self) -> Interrupts {
+        let mut irqs = Interrupts::new();
+        if self.reached_max_retries() {
+            irqs = irqs.transmission_fail();
+        }
+        if self.data_sent() {
+            irqs = irqs.transmission_ok();
+        }
+        if self.data_ready() {
+            irqs = irqs.data_ready();
+        }
+        irqs
+    }
 }
 
+/// Fixed dispatch priority for `Interrupts`, highest first, mirroring how a
+/// UART IIR register reports a single prioritized interrupt ID per read.
+const PRIORITY: [InterruptKind; 3] = [
+    InterruptKind::TransmissionFail,
+    InterruptKind::TransmissionOk,
+    InterruptKind::DataReady,
+];
+
+#[derive(Clone, Copy)]
 pub struct Interrupts(u8);
 
 impl Interrupts {
@@ -85,6 +136,11 @@ impl Interrupts {
     pub(crate) fn value(&self) -> u8 {
         self.0
     }
+    /// Returns the single highest-priority event set in this value, or
+    /// `None` if nothing is pending.
+    pub fn highest_priority(&self) -> Option<InterruptKind> {
+        self.into_iter().next()
+    }
 }
 
 impl From<u8> for Interrupts {
@@ -93,6 +149,35 @@ impl From<u8> for Interrupts {
     }
 }
 
+/// Iterates the events set in an `Interrupts` value in fixed priority
+/// order: `MAX_RT` first, then `TX_DS`, then `RX_DR`.
+pub struct InterruptsIter {
+    irqs: Interrupts,
+    idx: usize,
+}
+
+impl Iterator for InterruptsIter {
+    type Item = InterruptKind;
+    fn next(&mut self) -> Option<InterruptKind> {
+        while self.idx < PRIORITY.len() {
+            let kind = PRIORITY[self.idx];
+            self.idx += 1;
+            if self.irqs.contains(kind) {
+                return Some(kind);
+            }
+        }
+        None
+    }
+}
+
+impl IntoIterator for Interrupts {
+    type Item = InterruptKind;
+    type IntoIter = InterruptsIter;
+    fn into_iter(self) -> Self::IntoIter {
+        InterruptsIter { irqs: self, idx: 0 }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InterruptKind {
     TransmissionFail = 0b0001_0000,
@@ -120,6 +205,18 @@ impl FIFOStatus {
     pub fn rx_empty(&self) -> bool {
         self.0 & 1 != 0
     }
+
+    /// Returns `true` if there is room left to write another payload into
+    /// the TX FIFO.
+    pub fn tx_space_available(&self) -> bool {
+        !self.tx_full()
+    }
+
+    /// Returns `true` if there is a payload waiting to be read out of the
+    /// RX FIFO.
+    pub fn rx_data_available(&self) -> bool {
+        !self.rx_empty()
+    }
 }
 
 impl From<u8> for Status {
@@ -144,8 +241,9 @@ impl core::fmt::Debug for Status {
             let s = s.field("Data sent", &self.data_sent());
             let s = s.field("Reached max retries", &self.reached_max_retries());
             let s = match &self.data_pipe_available() {
-                None => s.field("No data ready to be read in FIFO", &true),
-                Some(pipe) => s.field("Data ready to be read on pipe", &pipe.pipe()),
+                Ok(None) => s.field("No data ready to be read in FIFO", &true),
+                Ok(Some(pipe)) => s.field("Data ready to be read on pipe", &pipe.pipe()),
+                Err(e) => s.field("Data pipe decode error", e),
             };
             let s = s.field("Transmission FIFO full", &self.tx_full());
             s.finish()
@@ -167,8 +265,9 @@ impl uDebug for Status {
             let s = s.field("Data sent", &self.data_sent())?;
             let s = s.field("Reached max retries", &self.reached_max_retries())?;
             let s = match &self.data_pipe_available() {
-                None => s.field("No data ready to be read in FIFO", &true)?,
-                Some(pipe) => s.field("Data ready to be read on pipe", &pipe.pipe())?,
+                Ok(None) => s.field("No data ready to be read in FIFO", &true)?,
+                Ok(Some(pipe)) => s.field("Data ready to be read on pipe", &pipe.pipe())?,
+                Err(e) => s.field("Data pipe decode error", e)?,
             };
             let s = s.field("Transmission FIFO full", &self.tx_full())?;
             s.finish()
@@ -176,20 +275,45 @@ impl uDebug for Status {
     }
 }
 
+#[cfg(feature = "micro-fmt")]
+impl uDebug for StatusError {
+    fn fmt<W: ?Sized>(&self, f: &mut Formatter<'_, W>) -> core::result::Result<(), W::Error>
+    where
+        W: uWrite,
+    {
+        match self {
+            StatusError::InvalidStatus => f.write_str("InvalidStatus"),
+            StatusError::InvalidPipe => f.write_str("InvalidPipe"),
+        }
+    }
+}
+
 #[cfg(feature = "de-fmt")]
-struct PipeReadStatus(Option<DataPipe>);
+struct PipeReadStatus(Result<Option<DataPipe>, StatusError>);
 
 #[cfg(feature = "de-fmt")]
 impl defmt::Format for PipeReadStatus {
     fn format(&self, fmt: defmt::Formatter) {
         use defmt::write;
         let available_str = match self.0 {
-            None => write!(fmt, "No data ready to be read in FIFO"),
-            Some(pipe) => write!(fmt, "Data ready to be read on pipe: {}", &pipe.pipe()),
+            Ok(None) => write!(fmt, "No data ready to be read in FIFO"),
+            Ok(Some(pipe)) => write!(fmt, "Data ready to be read on pipe: {}", &pipe.pipe()),
+            Err(e) => write!(fmt, "Data pipe decode error: {}", e),
         };
     }
 }
 
+#[cfg(feature = "de-fmt")]
+impl defmt::Format for StatusError {
+    fn format(&self, fmt: defmt::Formatter) {
+        use defmt::write;
+        match self {
+            StatusError::InvalidStatus => write!(fmt, "InvalidStatus"),
+            StatusError::InvalidPipe => write!(fmt, "InvalidPipe"),
+        }
+    }
+}
+
 #[cfg(feature = "de-fmt")]
 impl defmt::Format for Status {
     fn format(&self, fmt: defmt::Formatter) {
@@ -205,3 +329,79 @@ impl defmt::Format for Status {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_interrupts_folds_in_only_the_raised_bits() {
+        let status = Status::from(0b0000_0000);
+        assert_eq!(status.pending_interrupts().value(), 0);
+
+        let status = Status::from(0b0111_0000);
+        assert_eq!(
+            status.pending_interrupts().value(),
+            Interrupts::all().value()
+        );
+
+        let status = Status::from(0b0010_0000); // TX_DS only
+        assert_eq!(
+            status.pending_interrupts().value(),
+            Interrupts::new().transmission_ok().value()
+        );
+    }
+
+    #[test]
+    fn interrupts_iterate_max_rt_then_tx_ds_then_rx_dr() {
+        let mut iter = Interrupts::all().into_iter();
+        assert_eq!(iter.next(), Some(InterruptKind::TransmissionFail));
+        assert_eq!(iter.next(), Some(InterruptKind::TransmissionOk));
+        assert_eq!(iter.next(), Some(InterruptKind::DataReady));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn interrupts_iterate_only_set_bits_in_priority_order() {
+        let irqs = Interrupts::new().data_ready().transmission_fail();
+        let mut iter = irqs.into_iter();
+        assert_eq!(iter.next(), Some(InterruptKind::TransmissionFail));
+        assert_eq!(iter.next(), Some(InterruptKind::DataReady));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn highest_priority_picks_max_rt_over_other_pending_events() {
+        let irqs = Interrupts::all();
+        assert_eq!(irqs.highest_priority(), Some(InterruptKind::TransmissionFail));
+
+        let irqs = Interrupts::new().data_ready().transmission_ok();
+        assert_eq!(irqs.highest_priority(), Some(InterruptKind::TransmissionOk));
+
+        let irqs = Interrupts::new();
+        assert_eq!(irqs.highest_priority(), None);
+    }
+
+    #[test]
+    fn clear_mask_only_sets_currently_asserted_interrupt_bits() {
+        let status = Status::from(0b0010_0000); // TX_DS only
+        assert_eq!(status.clear_mask(), 0b0010_0000);
+
+        let status = Status::from(0b0111_0000);
+        assert_eq!(status.clear_mask(), Interrupts::all().value());
+    }
+
+    #[test]
+    fn ack_clears_only_the_requested_events_that_are_pending() {
+        // RX_DR and MAX_RT both asserted.
+        let status = Status::from(0b0101_0000);
+        let requested = Interrupts::new().data_ready();
+        assert_eq!(requested.value(), 0b0100_0000);
+        assert_eq!(status.ack(requested), 0b0100_0000);
+
+        // Asking to ack an event that isn't pending clears nothing.
+        let status = Status::from(0b0100_0000); // RX_DR only
+        let requested = Interrupts::new().transmission_fail();
+        assert_eq!(status.ack(requested), 0);
+    }
+}